@@ -1,25 +1,294 @@
 // Prevents additional console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use std::collections::HashMap;
 use std::process::{Command, Stdio};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tauri::Manager;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::Command as TokioCommand;
 
-#[derive(Clone, serde::Serialize)]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
 struct DeploymentUpdate {
     resource_id: String,
     status: String,
     message: String,
 }
 
+// A single line surfaced in the unified console: either a line read from a
+// `strands` child process, or a diagnostic logged by this app itself.
+#[derive(Clone, serde::Serialize)]
+struct ConsoleEvent {
+    level: String,
+    message: String,
+    source: String,
+    timestamp: u64,
+    task_id: Option<String>,
+}
+
+// A `\r`-terminated redraw of the current line (spinners, percent bars), as
+// opposed to a committed `cli-output` line.
+#[derive(Clone, serde::Serialize)]
+struct ProgressLine {
+    message: String,
+    replace_last: bool,
+    task_id: Option<String>,
+}
+
+fn unix_timestamp_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+// Infer a severity from common log prefixes, falling back to `default` when
+// the line carries no recognizable marker.
+fn infer_level(line: &str, default: &str) -> String {
+    // Compare on the uppercased string rather than slicing by byte length,
+    // since `line` may contain multi-byte UTF-8 and a byte-offset slice can
+    // land mid-codepoint and panic.
+    let trimmed = line.trim_start().to_ascii_uppercase();
+
+    if trimmed.starts_with("ERROR") {
+        "error".to_string()
+    } else if trimmed.starts_with("WARN") {
+        "warn".to_string()
+    } else if trimmed.starts_with("INFO") {
+        "info".to_string()
+    } else {
+        default.to_string()
+    }
+}
+
+fn emit_console_event(
+    window: &tauri::Window,
+    source: &str,
+    default_level: &str,
+    task_id: Option<String>,
+    message: String,
+) {
+    let event = ConsoleEvent {
+        level: infer_level(&message, default_level),
+        message,
+        source: source.to_string(),
+        timestamp: unix_timestamp_millis(),
+        task_id,
+    };
+    let _ = window.emit("console-event", event);
+}
+
+// One decoded chunk of stdout as split by `CrLfSplitter`: either a committed
+// line or a `\r`-redrawn progress line.
+#[derive(Debug, PartialEq)]
+enum StdoutEvent {
+    Line(Vec<u8>),
+    Progress(Vec<u8>),
+}
+
+// Splits a byte stream on both `\r` and `\n` instead of only `\n`, so a
+// `\r`-redrawn progress line (spinners, percent bars) is surfaced live as
+// it's redrawn rather than buffered until a newline finally arrives. A lone
+// `\r` is a progress redraw; `\r\n` and `\n` both commit the line (Python's
+// text-mode stdout on Windows — this app supports Windows — translates `\n`
+// to `\r\n`, so CRLF has to be treated as one terminator, not a redraw
+// followed by an empty commit). Pulled out of `read_stdout_with_progress` as
+// a plain state machine so the line-splitting logic can be unit tested
+// without spawning a real child process.
+#[derive(Default)]
+struct CrLfSplitter {
+    buf: Vec<u8>,
+    pending: Vec<u8>,
+}
+
+impl CrLfSplitter {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    // Feed newly read bytes in; returns every line/progress event that could
+    // be resolved from what's been fed so far.
+    fn feed(&mut self, chunk: &[u8]) -> Vec<StdoutEvent> {
+        self.pending.extend_from_slice(chunk);
+
+        let mut events = Vec::new();
+        let mut consumed = 0;
+
+        while consumed < self.pending.len() {
+            match self.pending[consumed] {
+                b'\n' => {
+                    events.push(StdoutEvent::Line(std::mem::take(&mut self.buf)));
+                    consumed += 1;
+                }
+                b'\r' => {
+                    // A `\r` at the very end of what's been fed so far might
+                    // be the first half of a `\r\n` pair whose `\n` hasn't
+                    // arrived yet — wait for more data before deciding.
+                    if consumed + 1 >= self.pending.len() {
+                        break;
+                    }
+
+                    if self.pending[consumed + 1] == b'\n' {
+                        events.push(StdoutEvent::Line(std::mem::take(&mut self.buf)));
+                        consumed += 2;
+                    } else {
+                        events.push(StdoutEvent::Progress(std::mem::take(&mut self.buf)));
+                        consumed += 1;
+                    }
+                }
+                b => {
+                    self.buf.push(b);
+                    consumed += 1;
+                }
+            }
+        }
+
+        self.pending.drain(..consumed);
+        events
+    }
+
+    // Call once the stream has hit EOF; flushes an unterminated final line,
+    // if any. A trailing lone `\r` with nothing after it is dropped rather
+    // than treated as a redraw.
+    fn finish(mut self) -> Option<Vec<u8>> {
+        if self.pending.last() == Some(&b'\r') {
+            self.pending.pop();
+        }
+        self.buf.extend_from_slice(&self.pending);
+
+        if self.buf.is_empty() {
+            None
+        } else {
+            Some(self.buf)
+        }
+    }
+}
+
+// Reads a child's stdout in chunks and routes each committed line or
+// progress redraw (see `CrLfSplitter`) to the right event: committed lines
+// are emitted as `cli-output`/`console-event` and are the only ones checked
+// for a structured deployment-status update; redraws are emitted as
+// `cli-output-progress`.
+async fn read_stdout_with_progress(
+    mut stdout: tokio::process::ChildStdout,
+    window: &tauri::Window,
+    task_id: &str,
+    deployments: &Arc<Mutex<HashMap<String, DeploymentUpdate>>>,
+) {
+    use tokio::io::AsyncReadExt;
+
+    let commit_line = |buf: Vec<u8>| {
+        let line = String::from_utf8_lossy(&buf).into_owned();
+
+        if let Ok(update) = serde_json::from_str::<DeploymentUpdate>(&line) {
+            deployments
+                .lock()
+                .unwrap()
+                .insert(update.resource_id.clone(), update.clone());
+            let _ = window.emit("deployment-update", update);
+        }
+
+        // Keep emitting the legacy `cli-output` event alongside the
+        // structured `console-event` so existing frontend listeners for it
+        // keep working.
+        let _ = window.emit("cli-output", line.clone());
+        emit_console_event(window, "stdout", "info", Some(task_id.to_string()), line);
+    };
+
+    let emit_progress = |buf: Vec<u8>| {
+        let message = String::from_utf8_lossy(&buf).into_owned();
+
+        let _ = window.emit(
+            "cli-output-progress",
+            ProgressLine {
+                message,
+                replace_last: true,
+                task_id: Some(task_id.to_string()),
+            },
+        );
+    };
+
+    let mut splitter = CrLfSplitter::new();
+    let mut chunk = [0u8; 4096];
+
+    loop {
+        let n = match stdout.read(&mut chunk).await {
+            Ok(0) | Err(_) => break,
+            Ok(n) => n,
+        };
+
+        for event in splitter.feed(&chunk[..n]) {
+            match event {
+                StdoutEvent::Line(bytes) => commit_line(bytes),
+                StdoutEvent::Progress(bytes) => emit_progress(bytes),
+            }
+        }
+    }
+
+    if let Some(bytes) = splitter.finish() {
+        commit_line(bytes);
+    }
+}
+
+// Forwards this crate's own `log::` calls into the same `console-event`
+// channel used for child-process output, so the frontend gets one unified,
+// filterable stream instead of two.
+struct ConsoleLogger {
+    app_handle: Mutex<Option<tauri::AppHandle>>,
+}
+
+impl ConsoleLogger {
+    fn set_app_handle(&self, handle: tauri::AppHandle) {
+        *self.app_handle.lock().unwrap() = Some(handle);
+    }
+}
+
+impl log::Log for ConsoleLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        let Some(handle) = self.app_handle.lock().unwrap().clone() else {
+            return;
+        };
+
+        let event = ConsoleEvent {
+            level: record.level().to_string().to_lowercase(),
+            message: record.args().to_string(),
+            source: "app".to_string(),
+            timestamp: unix_timestamp_millis(),
+            task_id: None,
+        };
+        let _ = handle.emit_all("console-event", event);
+    }
+
+    fn flush(&self) {}
+}
+
+static CONSOLE_LOGGER: ConsoleLogger = ConsoleLogger {
+    app_handle: Mutex::new(None),
+};
+
+// Tracks running `strands` child processes by caller-supplied task id so they
+// can be looked up again later (e.g. to abort them), plus the stdin handle of
+// any long-lived interactive session so the frontend can feed it input.
+#[derive(Default)]
+struct CliState {
+    processes: Arc<Mutex<HashMap<String, tokio::process::Child>>>,
+    stdins: Arc<Mutex<HashMap<String, tokio::process::ChildStdin>>>,
+    deployments: Arc<Mutex<HashMap<String, DeploymentUpdate>>>,
+}
+
 // Execute Python CLI command
 #[tauri::command]
 async fn execute_cli_command(
+    task_id: String,
     command: String,
     args: Vec<String>,
     window: tauri::Window,
+    state: tauri::State<'_, CliState>,
 ) -> Result<String, String> {
     let mut cmd = TokioCommand::new("strands");
     cmd.args(&args);
@@ -28,15 +297,14 @@ async fn execute_cli_command(
 
     let mut child = cmd.spawn().map_err(|e| e.to_string())?;
 
-    // Stream stdout
+    // Stream stdout, progress-aware so `\r`-redrawn lines don't buffer or spam
     if let Some(stdout) = child.stdout.take() {
-        let reader = BufReader::new(stdout);
-        let mut lines = reader.lines();
-        
+        let window_clone = window.clone();
+        let task_id_clone = task_id.clone();
+        let deployments = state.deployments.clone();
+
         tokio::spawn(async move {
-            while let Ok(Some(line)) = lines.next_line().await {
-                let _ = window.emit("cli-output", line);
-            }
+            read_stdout_with_progress(stdout, &window_clone, &task_id_clone, &deployments).await;
         });
     }
 
@@ -45,15 +313,40 @@ async fn execute_cli_command(
         let reader = BufReader::new(stderr);
         let mut lines = reader.lines();
         let window_clone = window.clone();
-        
+        let task_id_clone = task_id.clone();
+
         tokio::spawn(async move {
             while let Ok(Some(line)) = lines.next_line().await {
-                let _ = window_clone.emit("cli-error", line);
+                // Keep emitting the legacy `cli-error` event alongside the
+                // structured `console-event` so existing frontend listeners
+                // for it keep working.
+                let _ = window_clone.emit("cli-error", line.clone());
+                emit_console_event(&window_clone, "stderr", "warn", Some(task_id_clone.clone()), line);
             }
         });
     }
 
-    let status = child.wait().await.map_err(|e| e.to_string())?;
+    state.processes.lock().unwrap().insert(task_id.clone(), child);
+
+    // Poll for completion rather than awaiting the child directly, since the
+    // child now lives in shared state so `abort_cli_command` can reach it.
+    let status = loop {
+        let outcome = {
+            let mut processes = state.processes.lock().unwrap();
+            match processes.get_mut(&task_id) {
+                Some(child) => child.try_wait().map_err(|e| e.to_string())?,
+                None => return Err(format!("Task {} was aborted", task_id)),
+            }
+        };
+
+        if let Some(status) = outcome {
+            break status;
+        }
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    };
+
+    state.processes.lock().unwrap().remove(&task_id);
 
     if status.success() {
         Ok("Command executed successfully".to_string())
@@ -62,6 +355,114 @@ async fn execute_cli_command(
     }
 }
 
+// Abort a running CLI command started via `execute_cli_command`
+#[tauri::command]
+async fn abort_cli_command(
+    task_id: String,
+    window: tauri::Window,
+    state: tauri::State<'_, CliState>,
+) -> Result<(), String> {
+    let child = state.processes.lock().unwrap().remove(&task_id);
+
+    match child {
+        Some(mut child) => {
+            child.start_kill().map_err(|e| e.to_string())?;
+            let _ = window.emit("cli-aborted", task_id);
+            Ok(())
+        }
+        None => Err(format!("No running task with id {}", task_id)),
+    }
+}
+
+// Start a long-lived, interactive `strands` session whose stdin stays open so
+// the frontend can respond to prompts one line at a time via `send_cli_input`.
+#[tauri::command]
+async fn start_cli_session(
+    task_id: String,
+    command: String,
+    args: Vec<String>,
+    window: tauri::Window,
+    state: tauri::State<'_, CliState>,
+) -> Result<(), String> {
+    let mut cmd = TokioCommand::new("strands");
+    cmd.args(&args);
+    cmd.stdin(Stdio::piped());
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+
+    let mut child = cmd.spawn().map_err(|e| e.to_string())?;
+
+    let stdin = child
+        .stdin
+        .take()
+        .ok_or_else(|| "Failed to open stdin for CLI session".to_string())?;
+    state.stdins.lock().unwrap().insert(task_id.clone(), stdin);
+
+    // Stream stdout, progress-aware, ending the session once the child closes it
+    if let Some(stdout) = child.stdout.take() {
+        let window_clone = window.clone();
+        let task_id_clone = task_id.clone();
+        let processes = state.processes.clone();
+        let stdins = state.stdins.clone();
+        let deployments = state.deployments.clone();
+
+        tokio::spawn(async move {
+            read_stdout_with_progress(stdout, &window_clone, &task_id_clone, &deployments).await;
+
+            processes.lock().unwrap().remove(&task_id_clone);
+            stdins.lock().unwrap().remove(&task_id_clone);
+            let _ = window_clone.emit("cli-session-ended", task_id_clone);
+        });
+    }
+
+    // Stream stderr
+    if let Some(stderr) = child.stderr.take() {
+        let reader = BufReader::new(stderr);
+        let mut lines = reader.lines();
+        let window_clone = window.clone();
+        let task_id_clone = task_id.clone();
+
+        tokio::spawn(async move {
+            while let Ok(Some(line)) = lines.next_line().await {
+                // Keep emitting the legacy `cli-error` event alongside the
+                // structured `console-event` so existing frontend listeners
+                // for it keep working.
+                let _ = window_clone.emit("cli-error", line.clone());
+                emit_console_event(&window_clone, "stderr", "warn", Some(task_id_clone.clone()), line);
+            }
+        });
+    }
+
+    state.processes.lock().unwrap().insert(task_id, child);
+
+    Ok(())
+}
+
+// Write a line to a running session's stdin, started via `start_cli_session`
+#[tauri::command]
+async fn send_cli_input(
+    task_id: String,
+    line: String,
+    state: tauri::State<'_, CliState>,
+) -> Result<(), String> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut stdin = state
+        .stdins
+        .lock()
+        .unwrap()
+        .remove(&task_id)
+        .ok_or_else(|| format!("No active session for task {}", task_id))?;
+
+    let result = stdin.write_all(format!("{}\n", line).as_bytes()).await;
+    let flush_result = stdin.flush().await;
+
+    state.stdins.lock().unwrap().insert(task_id, stdin);
+
+    result.map_err(|e| e.to_string())?;
+    flush_result.map_err(|e| e.to_string())
+}
+
 // Read YAML configuration file
 #[tauri::command]
 async fn read_config_file(path: String) -> Result<String, String> {
@@ -78,6 +479,137 @@ async fn write_config_file(path: String, content: String) -> Result<(), String>
         .map_err(|e| e.to_string())
 }
 
+// Typed deployment config schema, as written to the YAML/TOML files the
+// editor reads and writes via `read_config_file`/`write_config_file`.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct DeploymentConfig {
+    name: String,
+    #[serde(default)]
+    region: Option<String>,
+    #[serde(default)]
+    resources: Vec<ResourceConfig>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct ResourceConfig {
+    id: String,
+    #[serde(rename = "type")]
+    resource_type: String,
+    #[serde(default)]
+    properties: serde_json::Value,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ConfigParseError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+#[derive(Clone, Copy)]
+enum ConfigFormat {
+    Yaml,
+    Toml,
+}
+
+fn config_format_from_path(path: &str) -> ConfigFormat {
+    match std::path::Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+    {
+        Some(ext) if ext.eq_ignore_ascii_case("toml") => ConfigFormat::Toml,
+        _ => ConfigFormat::Yaml,
+    }
+}
+
+fn parse_config_content(
+    content: &str,
+    format: ConfigFormat,
+) -> Result<DeploymentConfig, ConfigParseError> {
+    match format {
+        ConfigFormat::Yaml => serde_yaml::from_str(content).map_err(|e| {
+            let (line, column) = e
+                .location()
+                .map(|loc| (loc.line(), loc.column()))
+                .unwrap_or((0, 0));
+            ConfigParseError {
+                line,
+                column,
+                message: e.to_string(),
+            }
+        }),
+        ConfigFormat::Toml => toml::from_str(content).map_err(|e| {
+            let (line, column) = e.line_col().map(|(l, c)| (l + 1, c + 1)).unwrap_or((0, 0));
+            ConfigParseError {
+                line,
+                column,
+                message: e.to_string(),
+            }
+        }),
+    }
+}
+
+// Check that a parsed config has the fields a deployment actually needs,
+// independent of whether it parsed at all.
+fn validate_deployment_config(config: &DeploymentConfig) -> Result<(), ConfigParseError> {
+    if config.name.trim().is_empty() {
+        return Err(ConfigParseError {
+            line: 0,
+            column: 0,
+            message: "`name` is required".to_string(),
+        });
+    }
+
+    if config.resources.is_empty() {
+        return Err(ConfigParseError {
+            line: 0,
+            column: 0,
+            message: "at least one resource is required".to_string(),
+        });
+    }
+
+    for resource in &config.resources {
+        if resource.id.trim().is_empty() {
+            return Err(ConfigParseError {
+                line: 0,
+                column: 0,
+                message: "every resource needs an `id`".to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+// Parse a config file on disk into the typed deployment schema, dispatching
+// on its extension (`.yaml`/`.yml` vs `.toml`).
+#[tauri::command]
+async fn parse_config_file(path: String) -> Result<DeploymentConfig, ConfigParseError> {
+    let format = config_format_from_path(&path);
+    let content = tokio::fs::read_to_string(&path)
+        .await
+        .map_err(|e| ConfigParseError {
+            line: 0,
+            column: 0,
+            message: e.to_string(),
+        })?;
+
+    parse_config_content(&content, format)
+}
+
+// Parse and validate config content in memory, without touching disk, so the
+// editor can check on save before ever invoking `strands`.
+#[tauri::command]
+async fn validate_config(content: String, format: String) -> Result<(), ConfigParseError> {
+    let format = match format.to_lowercase().as_str() {
+        "toml" => ConfigFormat::Toml,
+        _ => ConfigFormat::Yaml,
+    };
+
+    let config = parse_config_content(&content, format)?;
+    validate_deployment_config(&config)
+}
+
 // Watch file for changes
 #[tauri::command]
 async fn watch_config_file(path: String, window: tauri::Window) -> Result<(), String> {
@@ -106,22 +638,217 @@ async fn watch_config_file(path: String, window: tauri::Window) -> Result<(), St
     Ok(())
 }
 
-// Get deployment status (mock for now, will integrate with real CLI)
+// Get a snapshot of the current deployment state, as tracked from parsed
+// `strands` status lines. Safe to re-query after a frontend reconnect.
 #[tauri::command]
-async fn get_deployment_status() -> Result<Vec<DeploymentUpdate>, String> {
-    // This will be replaced with actual deployment status from CLI
-    Ok(vec![])
+async fn get_deployment_status(
+    state: tauri::State<'_, CliState>,
+) -> Result<Vec<DeploymentUpdate>, String> {
+    Ok(state.deployments.lock().unwrap().values().cloned().collect())
+}
+
+// Periodically re-broadcasts the deployment snapshot so any connected
+// frontend stays in sync even if it missed individual `deployment-update`
+// events (e.g. it reconnected mid-deployment).
+fn spawn_deployment_watcher(app_handle: tauri::AppHandle) {
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(Duration::from_secs(2)).await;
+
+            let snapshot: Vec<DeploymentUpdate> = {
+                let state = app_handle.state::<CliState>();
+                state.deployments.lock().unwrap().values().cloned().collect()
+            };
+
+            let _ = app_handle.emit_all("deployment-status-sync", snapshot);
+        }
+    });
 }
 
 fn main() {
+    log::set_logger(&CONSOLE_LOGGER)
+        .map(|()| log::set_max_level(log::LevelFilter::Info))
+        .expect("failed to install console logger");
+
     tauri::Builder::default()
+        .manage(CliState::default())
+        .setup(|app| {
+            CONSOLE_LOGGER.set_app_handle(app.handle());
+            spawn_deployment_watcher(app.handle());
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             execute_cli_command,
+            abort_cli_command,
+            start_cli_session,
+            send_cli_input,
             read_config_file,
             write_config_file,
+            parse_config_file,
+            validate_config,
             watch_config_file,
             get_deployment_status,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infer_level_detects_common_prefixes() {
+        assert_eq!(infer_level("ERROR: boom", "info"), "error");
+        assert_eq!(infer_level("warn: careful", "info"), "warn");
+        assert_eq!(infer_level("INFO starting up", "warn"), "info");
+        assert_eq!(infer_level("just a plain line", "warn"), "warn");
+    }
+
+    #[test]
+    fn infer_level_does_not_panic_on_non_ascii_or_short_lines() {
+        // Regression test: a byte-offset slice at the prefix length used to
+        // panic here because byte 5 (the length of "ERROR") falls inside the
+        // third `é`, and "é" is shorter than any of the prefixes.
+        assert_eq!(infer_level("éééx", "info"), "info");
+        assert_eq!(infer_level("é", "warn"), "warn");
+        assert_eq!(infer_level("", "warn"), "warn");
+    }
+
+    #[test]
+    fn crlf_is_a_single_committed_line() {
+        let mut splitter = CrLfSplitter::new();
+        let events = splitter.feed(b"hello\r\nworld\n");
+        assert_eq!(
+            events,
+            vec![
+                StdoutEvent::Line(b"hello".to_vec()),
+                StdoutEvent::Line(b"world".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn lone_cr_is_a_progress_redraw() {
+        let mut splitter = CrLfSplitter::new();
+        // The `\r` can't be resolved until a following byte disambiguates it
+        // from a `\r\n` pair.
+        let events = splitter.feed(b"50%\rx");
+        assert_eq!(events, vec![StdoutEvent::Progress(b"50%".to_vec())]);
+    }
+
+    #[test]
+    fn lone_lf_is_a_committed_line() {
+        let mut splitter = CrLfSplitter::new();
+        let events = splitter.feed(b"done\n");
+        assert_eq!(events, vec![StdoutEvent::Line(b"done".to_vec())]);
+    }
+
+    #[test]
+    fn cr_split_across_chunk_boundary_is_still_one_commit() {
+        let mut splitter = CrLfSplitter::new();
+        let events = splitter.feed(b"hello\r");
+        assert!(events.is_empty(), "should wait for the byte after \\r");
+
+        let events = splitter.feed(b"\nworld\n");
+        assert_eq!(
+            events,
+            vec![
+                StdoutEvent::Line(b"hello".to_vec()),
+                StdoutEvent::Line(b"world".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn trailing_lone_cr_at_eof_is_dropped() {
+        let mut splitter = CrLfSplitter::new();
+        let events = splitter.feed(b"partial");
+        assert!(events.is_empty());
+
+        let events = splitter.feed(b"\r");
+        assert!(events.is_empty(), "still waiting for lookahead");
+
+        assert_eq!(splitter.finish(), Some(b"partial".to_vec()));
+    }
+
+    #[test]
+    fn unterminated_trailing_text_is_flushed_on_finish() {
+        let mut splitter = CrLfSplitter::new();
+        let events = splitter.feed(b"no newline at all");
+        assert!(events.is_empty());
+
+        assert_eq!(splitter.finish(), Some(b"no newline at all".to_vec()));
+    }
+
+    #[test]
+    fn parses_valid_yaml_config() {
+        let content = "name: demo\nresources:\n  - id: a\n    type: bucket\n";
+        let config = parse_config_content(content, ConfigFormat::Yaml).unwrap();
+        assert_eq!(config.name, "demo");
+        assert_eq!(config.resources.len(), 1);
+        assert_eq!(config.resources[0].id, "a");
+    }
+
+    #[test]
+    fn yaml_parse_error_reports_location() {
+        let content = "name: [unterminated";
+        let err = parse_config_content(content, ConfigFormat::Yaml).unwrap_err();
+        assert!(err.line > 0, "expected a 1-based line number, got {}", err.line);
+    }
+
+    #[test]
+    fn validate_requires_a_name() {
+        let config = DeploymentConfig {
+            name: "".to_string(),
+            region: None,
+            resources: vec![ResourceConfig {
+                id: "a".to_string(),
+                resource_type: "bucket".to_string(),
+                properties: serde_json::Value::Null,
+            }],
+        };
+        let err = validate_deployment_config(&config).unwrap_err();
+        assert_eq!(err.message, "`name` is required");
+    }
+
+    #[test]
+    fn validate_requires_at_least_one_resource() {
+        let config = DeploymentConfig {
+            name: "demo".to_string(),
+            region: None,
+            resources: vec![],
+        };
+        let err = validate_deployment_config(&config).unwrap_err();
+        assert_eq!(err.message, "at least one resource is required");
+    }
+
+    #[test]
+    fn validate_requires_every_resource_to_have_an_id() {
+        let config = DeploymentConfig {
+            name: "demo".to_string(),
+            region: None,
+            resources: vec![ResourceConfig {
+                id: "".to_string(),
+                resource_type: "bucket".to_string(),
+                properties: serde_json::Value::Null,
+            }],
+        };
+        let err = validate_deployment_config(&config).unwrap_err();
+        assert_eq!(err.message, "every resource needs an `id`");
+    }
+
+    #[test]
+    fn validate_accepts_a_well_formed_config() {
+        let config = DeploymentConfig {
+            name: "demo".to_string(),
+            region: Some("us-east-1".to_string()),
+            resources: vec![ResourceConfig {
+                id: "a".to_string(),
+                resource_type: "bucket".to_string(),
+                properties: serde_json::Value::Null,
+            }],
+        };
+        assert!(validate_deployment_config(&config).is_ok());
+    }
+}